@@ -0,0 +1,60 @@
+//! Builds the subset of the generated `pyproject.toml` that's resolved from
+//! a project's existing configuration, rather than copied verbatim from the
+//! flake8 config being converted.
+
+use ruff_linter::settings::types::PythonVersion;
+
+use crate::pep621::Project;
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct Configuration {
+    pub(crate) target_version: Option<PythonVersion>,
+}
+
+/// Resolve the `Configuration` to write out for `project`.
+///
+/// `cli_target_version` takes precedence when the user passed
+/// `--target-version` on the command line; otherwise the floor is inferred
+/// from `project`'s `requires-python`, so the generated config carries an
+/// accurate `target-version` without the user having to set one by hand.
+pub(crate) fn convert(
+    project: &Project,
+    cli_target_version: Option<PythonVersion>,
+) -> Configuration {
+    Configuration {
+        target_version: project.resolve_target_version(cli_target_version),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use pep440_rs::VersionSpecifiers;
+    use ruff_linter::settings::types::PythonVersion;
+
+    use super::convert;
+    use crate::pep621::Project;
+
+    #[test]
+    fn convert_infers_target_version_from_requires_python() {
+        let project = Project {
+            requires_python: Some(VersionSpecifiers::from_str(">=3.10").unwrap()),
+        };
+        assert_eq!(
+            convert(&project, None).target_version,
+            Some(PythonVersion::Py310)
+        );
+    }
+
+    #[test]
+    fn convert_prefers_explicit_cli_target_version() {
+        let project = Project {
+            requires_python: Some(VersionSpecifiers::from_str(">=3.9").unwrap()),
+        };
+        assert_eq!(
+            convert(&project, Some(PythonVersion::Py312)).target_version,
+            Some(PythonVersion::Py312)
+        );
+    }
+}
@@ -0,0 +1,2 @@
+mod converter;
+mod pep621;
@@ -1,10 +1,96 @@
 //! Extract PEP 621 configuration settings from a pyproject.toml.
 
-use pep440_rs::VersionSpecifiers;
+use std::str::FromStr;
+
+use pep440_rs::{Version, VersionSpecifiers};
 use serde::{Deserialize, Serialize};
 
+use ruff_linter::settings::types::PythonVersion;
+
+/// Python versions in ascending order, for resolving the lowest version
+/// compatible with a `requires-python` specifier.
+const SUPPORTED_VERSIONS: &[PythonVersion] = &[
+    PythonVersion::Py37,
+    PythonVersion::Py38,
+    PythonVersion::Py39,
+    PythonVersion::Py310,
+    PythonVersion::Py311,
+    PythonVersion::Py312,
+    PythonVersion::Py313,
+];
+
 #[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Default)]
 pub(crate) struct Project {
     #[serde(alias = "requires-python", alias = "requires_python")]
     pub(crate) requires_python: Option<VersionSpecifiers>,
 }
+
+impl Project {
+    /// Infer the minimum Python version supported by the project from its
+    /// `requires-python` specifier.
+    ///
+    /// For example, `>=3.9,<3.13` resolves to [`PythonVersion::Py39`].
+    fn infer_target_version(&self) -> Option<PythonVersion> {
+        let requires_python = self.requires_python.as_ref()?;
+        SUPPORTED_VERSIONS.iter().copied().find(|version| {
+            let (major, minor) = version.as_tuple();
+            Version::from_str(&format!("{major}.{minor}"))
+                .is_ok_and(|version| requires_python.contains(&version))
+        })
+    }
+
+    /// Resolve the `target-version` to write into the generated `pyproject.toml`.
+    ///
+    /// An explicit `target-version` (e.g. passed on the CLI, or already present in
+    /// the user's `setup.cfg`/`tox.ini`) always wins; otherwise, fall back to the
+    /// floor inferred from `requires-python`, so the generated config carries an
+    /// accurate `target-version` without the user having to set one by hand.
+    pub(crate) fn resolve_target_version(
+        &self,
+        explicit_target_version: Option<PythonVersion>,
+    ) -> Option<PythonVersion> {
+        explicit_target_version.or_else(|| self.infer_target_version())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use pep440_rs::VersionSpecifiers;
+    use ruff_linter::settings::types::PythonVersion;
+
+    use super::Project;
+
+    #[test]
+    fn resolves_lower_bound_of_requires_python() {
+        let project = Project {
+            requires_python: Some(VersionSpecifiers::from_str(">=3.9,<3.13").unwrap()),
+        };
+        assert_eq!(
+            project.resolve_target_version(None),
+            Some(PythonVersion::Py39)
+        );
+    }
+
+    #[test]
+    fn explicit_target_version_takes_precedence() {
+        let project = Project {
+            requires_python: Some(VersionSpecifiers::from_str(">=3.9").unwrap()),
+        };
+        assert_eq!(
+            project.resolve_target_version(Some(PythonVersion::Py311)),
+            Some(PythonVersion::Py311)
+        );
+    }
+
+    #[test]
+    fn no_requires_python_resolves_to_explicit_or_none() {
+        let project = Project::default();
+        assert_eq!(project.resolve_target_version(None), None);
+        assert_eq!(
+            project.resolve_target_version(Some(PythonVersion::Py38)),
+            Some(PythonVersion::Py38)
+        );
+    }
+}
@@ -0,0 +1,14 @@
+//! Maps a rule's noqa code to its [`Rule`] variant.
+//!
+//! This only reproduces the codes touched by the NPY201/RUF102 work; the
+//! real table covers every rule Ruff implements.
+
+use crate::registry::Rule;
+
+pub(crate) fn code_to_rule(code: &str) -> Option<Rule> {
+    Some(match code {
+        "NPY201" => Rule::Numpy2Deprecation,
+        "RUF102" => Rule::DeprecatedSymbolReplacement,
+        _ => return None,
+    })
+}
@@ -0,0 +1,13 @@
+//! The set of rules Ruff implements.
+//!
+//! This only reproduces the variants touched by the NPY201/RUF102 work; the
+//! real registry lists every rule Ruff implements and is generated from
+//! `codes.rs`.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rule {
+    /// `NPY201`
+    Numpy2Deprecation,
+    /// `RUF102`
+    DeprecatedSymbolReplacement,
+}
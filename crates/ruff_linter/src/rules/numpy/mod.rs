@@ -0,0 +1,3 @@
+//! Rules that flag deprecated or removed NumPy API usage (the `NPY` category).
+
+pub(crate) mod rules;
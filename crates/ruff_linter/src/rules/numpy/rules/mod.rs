@@ -0,0 +1,3 @@
+pub(crate) use numpy_2_0_deprecation::*;
+
+mod numpy_2_0_deprecation;
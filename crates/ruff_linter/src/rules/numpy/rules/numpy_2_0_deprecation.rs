@@ -1,3 +1,4 @@
+use pep440_rs::Version;
 use ruff_diagnostics::{Diagnostic, Edit, Fix, FixAvailability, Violation};
 use ruff_macros::{derive_message_formats, violation};
 use ruff_python_ast::Expr;
@@ -6,6 +7,11 @@ use ruff_text_size::Ranged;
 use crate::checkers::ast::Checker;
 use crate::importer::ImportRequest;
 
+/// The NumPy version in which the members covered by this rule were removed.
+fn numpy_2_0() -> Version {
+    Version::new([2, 0])
+}
+
 /// ## What it does
 /// Checks for uses of NumPy functions and constants that were removed from
 /// the main namespace in NumPy 2.0.
@@ -16,7 +22,9 @@ use crate::importer::ImportRequest;
 /// accessing constants, dtypes, and functions.
 ///
 /// As part of this overhaul, a variety of deprecated NumPy functions and
-/// constants were removed from the main namespace.
+/// constants were removed from the main namespace. The 2.0 release also
+/// relocated or privatized several submodules, such as `numpy.core` moving
+/// under the private `numpy._core`; this rule flags those accesses too.
 ///
 /// The majority of these functions and constants can be automatically replaced
 /// by other members of the NumPy API, even prior to NumPy 2.0, or by
@@ -24,6 +32,15 @@ use crate::importer::ImportRequest;
 /// removed members, along with automatic fixes for any backwards-compatible
 /// replacements.
 ///
+/// If the [`numpy-version`] setting isn't set, Ruff assumes the project hasn't
+/// yet migrated to NumPy 2.0, and reports these members as pending deprecations
+/// rather than as already-removed.
+///
+/// This rule's `existing` → replacement tables hardcode NumPy's own migration
+/// guidance; for migrating away from deprecated symbols in other libraries,
+/// see the user-configurable `migrations` setting and the
+/// `deprecated-symbol-replacement` rule it drives.
+///
 /// ## Examples
 /// ```python
 /// import numpy as np
@@ -41,10 +58,18 @@ use crate::importer::ImportRequest;
 /// arr2 = [np.float64(1.5), np.float64(5.1)]
 /// np.round(arr2)
 /// ```
+///
+/// ## Options
+/// - `numpy-version`
+///
+/// [`numpy-version`]: https://docs.astral.sh/ruff/settings/#numpy-version
 #[violation]
 pub struct Numpy2Deprecation {
     existing: String,
     migration_guide: Option<String>,
+    /// Whether the configured `numpy-version` is known to have already
+    /// removed `existing`, as opposed to merely deprecating it.
+    removed: bool,
 }
 
 impl Violation for Numpy2Deprecation {
@@ -55,12 +80,21 @@ impl Violation for Numpy2Deprecation {
         let Numpy2Deprecation {
             existing,
             migration_guide,
+            removed,
         } = self;
-        match migration_guide {
-            Some(migration_guide) => {
-                format!("`np.{existing}` will be removed in NumPy 2.0. {migration_guide}",)
+        match (removed, migration_guide) {
+            (true, Some(migration_guide)) => {
+                format!("`np.{existing}` was removed in NumPy 2.0. {migration_guide}")
+            }
+            (true, None) => {
+                format!("`np.{existing}` was removed without replacement in NumPy 2.0.")
+            }
+            (false, Some(migration_guide)) => {
+                format!("`np.{existing}` will be removed in NumPy 2.0. {migration_guide}")
+            }
+            (false, None) => {
+                format!("`np.{existing}` will be removed without replacement in NumPy 2.0.")
             }
-            None => format!("`np.{existing}` will be removed without replacement in NumPy 2.0."),
         }
     }
 
@@ -68,6 +102,7 @@ impl Violation for Numpy2Deprecation {
         let Numpy2Deprecation {
             existing: _,
             migration_guide,
+            removed: _,
         } = self;
         migration_guide.clone()
     }
@@ -76,13 +111,23 @@ impl Violation for Numpy2Deprecation {
 #[derive(Debug)]
 struct Replacement<'a> {
     existing: &'a str,
+    /// The NumPy version in which `existing` was (or will be) removed.
+    since: Version,
     details: Details<'a>,
 }
 
 #[derive(Debug)]
 enum Details<'a> {
     /// The deprecated member can be replaced by another member in the NumPy API.
-    AutoImport { path: &'a str, name: &'a str },
+    AutoImport {
+        path: &'a str,
+        name: &'a str,
+        /// The NumPy version in which `path.name` became importable, or `None`
+        /// if it's available in every NumPy version this rule cares about.
+        /// Fixes are only safe to apply automatically once the configured
+        /// `numpy-version` is known to be at or above this floor.
+        available_since: Option<Version>,
+    },
     /// The deprecated member can be replaced by a member of the Python standard library.
     AutoPurePython { python_expr: &'a str },
     /// The deprecated member can be replaced by a manual migration.
@@ -92,7 +137,7 @@ enum Details<'a> {
 impl Details<'_> {
     fn guideline(&self) -> Option<String> {
         match self {
-            Details::AutoImport { path, name } => Some(format!("Use `{path}.{name}` instead.")),
+            Details::AutoImport { path, name, .. } => Some(format!("Use `{path}.{name}` instead.")),
             Details::AutoPurePython { python_expr } => {
                 Some(format!("Use `{python_expr}` instead."))
             }
@@ -110,352 +155,496 @@ pub(crate) fn numpy_2_0_deprecation(checker: &mut Checker, expr: &Expr) {
             // NumPy's main namespace np.* members removed in 2.0
             ["numpy", "add_docstring"] => Some(Replacement {
                 existing: "add_docstring",
+                since: numpy_2_0(),
                 details: Details::AutoImport {
                     path: "numpy.lib",
                     name: "add_docstring",
+                    available_since: Some(numpy_2_0()),
                 },
             }),
             ["numpy", "add_newdoc"] => Some(Replacement {
                 existing: "add_newdoc",
+                since: numpy_2_0(),
                 details: Details::AutoImport {
                     path: "numpy.lib",
                     name: "add_newdoc",
+                    available_since: None,
                 },
             }),
             ["numpy", "add_newdoc_ufunc"] => Some(Replacement {
                 existing: "add_newdoc_ufunc",
+                since: numpy_2_0(),
                 details: Details::Manual {
                     guideline: Some("`add_newdoc_ufunc` is an internal function."),
                 },
             }),
             ["numpy", "asfarray"] => Some(Replacement {
                 existing: "asfarray",
+                since: numpy_2_0(),
                 details: Details::Manual {
                     guideline: Some("Use `np.asarray` with a `float` dtype instead."),
                 },
             }),
             ["numpy", "byte_bounds"] => Some(Replacement {
                 existing: "byte_bounds",
+                since: numpy_2_0(),
                 details: Details::AutoImport {
                     path: "numpy.lib.array_utils",
                     name: "byte_bounds",
+                    available_since: Some(numpy_2_0()),
                 },
             }),
             ["numpy", "cast"] => Some(Replacement {
                 existing: "cast",
+                since: numpy_2_0(),
                 details: Details::Manual {
                     guideline: Some("Use `np.asarray(arr, dtype=dtype)` instead."),
                 },
             }),
             ["numpy", "cfloat"] => Some(Replacement {
                 existing: "cfloat",
+                since: numpy_2_0(),
                 details: Details::AutoImport {
                     path: "numpy",
                     name: "complex128",
+                    available_since: None,
                 },
             }),
             ["numpy", "clongfloat"] => Some(Replacement {
                 existing: "clongfloat",
+                since: numpy_2_0(),
                 details: Details::AutoImport {
                     path: "numpy",
                     name: "clongdouble",
+                    available_since: None,
                 },
             }),
             ["numpy", "compat"] => Some(Replacement {
                 existing: "compat",
+                since: numpy_2_0(),
                 details: Details::Manual {
                     guideline: Some("Python 2 is no longer supported."),
                 },
             }),
             ["numpy", "complex_"] => Some(Replacement {
                 existing: "complex_",
+                since: numpy_2_0(),
                 details: Details::AutoImport {
                     path: "numpy",
                     name: "complex128",
+                    available_since: None,
                 },
             }),
             ["numpy", "DataSource"] => Some(Replacement {
                 existing: "DataSource",
+                since: numpy_2_0(),
                 details: Details::AutoImport {
                     path: "numpy.lib.npyio",
                     name: "DataSource",
+                    available_since: None,
                 },
             }),
             ["numpy", "deprecate"] => Some(Replacement {
                 existing: "deprecate",
+                since: numpy_2_0(),
                 details: Details::Manual {
                     guideline: Some("Emit `DeprecationWarning` with `warnings.warn` directly, or use `typing.deprecated`."),
                 },
             }),
             ["numpy", "deprecate_with_doc"] => Some(Replacement {
                 existing: "deprecate_with_doc",
+                since: numpy_2_0(),
                 details: Details::Manual {
                     guideline: Some("Emit `DeprecationWarning` with `warnings.warn` directly, or use `typing.deprecated`."),
                 },
             }),
             ["numpy", "disp"] => Some(Replacement {
                 existing: "disp",
+                since: numpy_2_0(),
                 details: Details::Manual {
                     guideline: Some("Use a dedicated print function instead."),
                 },
             }),
             ["numpy", "fastCopyAndTranspose"] => Some(Replacement {
                 existing: "fastCopyAndTranspose",
+                since: numpy_2_0(),
                 details: Details::Manual {
                     guideline: Some("Use `arr.T.copy()` instead."),
                 },
             }),
             ["numpy", "find_common_type"] => Some(Replacement {
                 existing: "find_common_type",
+                since: numpy_2_0(),
                 details: Details::Manual {
                     guideline: Some("Use `numpy.promote_types` or `numpy.result_type` instead. To achieve semantics for the `scalar_types` argument, use `numpy.result_type` and pass the Python values `0`, `0.0`, or `0j`."),
                 },
             }),
             ["numpy", "get_array_wrap"] => Some(Replacement {
                 existing: "get_array_wrap",
+                since: numpy_2_0(),
                 details: Details::Manual {
                     guideline: None,
                 },
             }),
             ["numpy", "float_"] => Some(Replacement {
                 existing: "float_",
+                since: numpy_2_0(),
                 details: Details::AutoImport {
                     path: "numpy",
                     name: "float64",
+                    available_since: None,
                 },
             }),
             ["numpy", "geterrobj"] => Some(Replacement {
                 existing: "geterrobj",
+                since: numpy_2_0(),
                 details: Details::Manual {
                     guideline: Some("Use the `np.errstate` context manager instead."),
                 },
             }),
             ["numpy", "INF"] => Some(Replacement {
                 existing: "INF",
+                since: numpy_2_0(),
                 details: Details::AutoImport {
                     path: "numpy",
                     name: "inf",
+                    available_since: None,
                 },
             }),
             ["numpy", "Inf"] => Some(Replacement {
                 existing: "Inf",
+                since: numpy_2_0(),
                 details: Details::AutoImport {
                     path: "numpy",
                     name: "inf",
+                    available_since: None,
                 },
             }),
             ["numpy", "Infinity"] => Some(Replacement {
                 existing: "Infinity",
+                since: numpy_2_0(),
                 details: Details::AutoImport {
                     path: "numpy",
                     name: "inf",
+                    available_since: None,
                 },
             }),
             ["numpy", "infty"] => Some(Replacement {
                 existing: "infty",
+                since: numpy_2_0(),
                 details: Details::AutoImport {
                     path: "numpy",
                     name: "inf",
+                    available_since: None,
                 },
             }),
             ["numpy", "issctype"] => Some(Replacement {
                 existing: "issctype",
+                since: numpy_2_0(),
                 details: Details::Manual {
                     guideline: None,
                 },
             }),
             ["numpy", "issubclass_"] => Some(Replacement {
                 existing: "issubclass_",
+                since: numpy_2_0(),
                 details: Details::AutoPurePython {
                     python_expr: "issubclass",
                 },
             }),
             ["numpy", "issubsctype"] => Some(Replacement {
                 existing: "issubsctype",
+                since: numpy_2_0(),
                 details: Details::AutoImport {
                     path: "numpy",
                     name: "issubdtype",
+                    available_since: None,
                 },
             }),
             ["numpy", "mat"] => Some(Replacement {
                 existing: "mat",
+                since: numpy_2_0(),
                 details: Details::AutoImport {
                     path: "numpy",
                     name: "asmatrix",
+                    available_since: None,
                 },
             }),
             ["numpy", "maximum_sctype"] => Some(Replacement {
                 existing: "maximum_sctype",
+                since: numpy_2_0(),
                 details: Details::Manual {
                     guideline: None,
                 },
             }),
             ["numpy", "NaN"] => Some(Replacement {
                 existing: "NaN",
+                since: numpy_2_0(),
                 details: Details::AutoImport {
                     path: "numpy",
                     name: "nan",
+                    available_since: None,
                 },
             }),
             ["numpy", "nbytes"] => Some(Replacement {
                 existing: "nbytes",
+                since: numpy_2_0(),
                 details: Details::Manual {
                     guideline: Some("Use `np.dtype(<dtype>).itemsize` instead."),
                 },
             }),
             ["numpy", "NINF"] => Some(Replacement {
                 existing: "NINF",
+                since: numpy_2_0(),
                 details: Details::AutoPurePython {
                     python_expr: "-np.inf",
                 },
             }),
             ["numpy", "NZERO"] => Some(Replacement {
                 existing: "NZERO",
+                since: numpy_2_0(),
                 details: Details::AutoPurePython {
                     python_expr: "-0.0",
                 },
             }),
             ["numpy", "longcomplex"] => Some(Replacement {
                 existing: "longcomplex",
+                since: numpy_2_0(),
                 details: Details::AutoImport {
                     path: "numpy",
                     name: "clongdouble",
+                    available_since: None,
                 },
             }),
             ["numpy", "longfloat"] => Some(Replacement {
                 existing: "longfloat",
+                since: numpy_2_0(),
                 details: Details::AutoImport {
                     path: "numpy",
                     name: "longdouble",
+                    available_since: None,
                 },
             }),
             ["numpy", "lookfor"] => Some(Replacement {
                 existing: "lookfor",
+                since: numpy_2_0(),
                 details: Details::Manual {
                     guideline: Some("Search NumPy’s documentation directly."),
                 },
             }),
             ["numpy", "obj2sctype"] => Some(Replacement {
                 existing: "obj2sctype",
+                since: numpy_2_0(),
                 details: Details::Manual {
                     guideline: None,
                 },
             }),
             ["numpy", "PINF"] => Some(Replacement {
                 existing: "PINF",
+                since: numpy_2_0(),
                 details: Details::AutoImport {
                     path: "numpy",
                     name: "inf",
+                    available_since: None,
                 },
             }),
             ["numpy", "PZERO"] => Some(Replacement {
                 existing: "PZERO",
+                since: numpy_2_0(),
                 details: Details::AutoPurePython { python_expr: "0.0" },
             }),
             ["numpy", "recfromcsv"] => Some(Replacement {
                 existing: "recfromcsv",
+                since: numpy_2_0(),
                 details: Details::Manual {
                     guideline: Some("Use `np.genfromtxt` with comma delimiter instead."),
                 },
             }),
             ["numpy", "recfromtxt"] => Some(Replacement {
                 existing: "recfromtxt",
+                since: numpy_2_0(),
                 details: Details::Manual {
                     guideline: Some("Use `np.genfromtxt` instead."),
                 },
             }),
             ["numpy", "round_"] => Some(Replacement {
                 existing: "round_",
+                since: numpy_2_0(),
                 details: Details::AutoImport {
                     path: "numpy",
                     name: "round",
+                    available_since: None,
                 },
             }),
             ["numpy", "safe_eval"] => Some(Replacement {
                 existing: "safe_eval",
+                since: numpy_2_0(),
                 details: Details::AutoImport {
                     path: "ast",
                     name: "literal_eval",
+                    available_since: None,
                 },
             }),
             ["numpy", "sctype2char"] => Some(Replacement {
                 existing: "sctype2char",
+                since: numpy_2_0(),
                 details: Details::Manual {
                     guideline: None,
                 },
             }),
             ["numpy", "sctypes"] => Some(Replacement {
                 existing: "sctypes",
+                since: numpy_2_0(),
                 details: Details::Manual {
                     guideline: None,
                 },
             }),
             ["numpy", "seterrobj"] => Some(Replacement {
                 existing: "seterrobj",
+                since: numpy_2_0(),
                 details: Details::Manual {
                     guideline: Some("Use the `np.errstate` context manager instead."),
                 },
             }),
             ["numpy", "set_string_function"] => Some(Replacement {
                 existing: "set_string_function",
+                since: numpy_2_0(),
                 details: Details::Manual {
                     guideline: Some("Use `np.set_printoptions` for custom printing of NumPy objects."),
                 },
             }),
             ["numpy", "singlecomplex"] => Some(Replacement {
                 existing: "singlecomplex",
+                since: numpy_2_0(),
                 details: Details::AutoImport {
                     path: "numpy",
                     name: "complex64",
+                    available_since: None,
                 },
             }),
             ["numpy", "string_"] => Some(Replacement {
                 existing: "string_",
+                since: numpy_2_0(),
                 details: Details::AutoImport {
                     path: "numpy",
                     name: "bytes_",
+                    available_since: None,
                 },
             }),
             ["numpy", "source"] => Some(Replacement {
                 existing: "source",
+                since: numpy_2_0(),
                 details: Details::AutoImport {
                     path: "inspect",
                     name: "getsource",
+                    available_since: None,
                 },
             }),
             ["numpy", "tracemalloc_domain"] => Some(Replacement {
                 existing: "tracemalloc_domain",
+                since: numpy_2_0(),
                 details: Details::AutoImport {
                     path: "numpy.lib",
                     name: "tracemalloc_domain",
+                    available_since: Some(numpy_2_0()),
                 },
             }),
             ["numpy", "unicode_"] => Some(Replacement {
                 existing: "unicode_",
+                since: numpy_2_0(),
                 details: Details::AutoImport {
                     path: "numpy",
                     name: "str_",
+                    available_since: None,
                 },
             }),
             ["numpy", "who"] => Some(Replacement {
                 existing: "who",
+                since: numpy_2_0(),
                 details: Details::Manual {
                     guideline: Some("Use an IDE variable explorer or `locals()` instead."),
                 },
             }),
+            // `numpy.core` was privatized to `numpy._core` in 2.0. A handful of its
+            // submodules have stable public aliases; everything else is internal-only.
+            //
+            // These two are matched as exact (non-prefix) paths: the checker visits the
+            // `numpy.core.records`/`numpy.core.defchararray` attribute node on its own, even
+            // when it's the base of a longer chain like `numpy.core.records.fromarrays(...)`,
+            // so the fix only ever replaces that node and leaves any trailing `.fromarrays`
+            // attribute access untouched.
+            ["numpy", "core", "records"] => Some(Replacement {
+                existing: "core.records",
+                since: numpy_2_0(),
+                details: Details::AutoImport {
+                    path: "numpy",
+                    name: "rec",
+                    available_since: Some(numpy_2_0()),
+                },
+            }),
+            ["numpy", "core", "defchararray"] => Some(Replacement {
+                existing: "core.defchararray",
+                since: numpy_2_0(),
+                details: Details::AutoImport {
+                    path: "numpy",
+                    name: "char",
+                    available_since: Some(numpy_2_0()),
+                },
+            }),
+            ["numpy", "core", ..] => Some(Replacement {
+                existing: "core",
+                since: numpy_2_0(),
+                details: Details::Manual {
+                    guideline: Some(
+                        "`numpy.core` is now private (`numpy._core`); use the corresponding top-level `numpy` member instead.",
+                    ),
+                },
+            }),
+            // Several `numpy.lib` helpers were moved into private implementation modules in
+            // 2.0, reachable only as internals of the `numpy.lib` subpackages that replaced them.
+            ["numpy", "lib", "function_base", ..] => Some(Replacement {
+                existing: "lib.function_base",
+                since: numpy_2_0(),
+                details: Details::Manual {
+                    guideline: Some("`numpy.lib.function_base` is now private; use the corresponding top-level `numpy` member instead."),
+                },
+            }),
+            ["numpy", "lib", "arraysetops", ..] => Some(Replacement {
+                existing: "lib.arraysetops",
+                since: numpy_2_0(),
+                details: Details::Manual {
+                    guideline: Some("`numpy.lib.arraysetops` is now private; use the corresponding top-level `numpy` member instead."),
+                },
+            }),
             _ => None,
         });
 
     if let Some(replacement) = maybe_replacement {
+        // If the user has configured a `numpy-version` at or above the version in which
+        // `existing` was removed, report it as already-removed rather than merely deprecated.
+        // Absent a configured floor, assume the deprecation hasn't yet become a removal.
+        let removed = checker
+            .settings
+            .numpy_version
+            .as_ref()
+            .is_some_and(|numpy_version| *numpy_version >= replacement.since);
+
         let mut diagnostic = Diagnostic::new(
             Numpy2Deprecation {
                 existing: replacement.existing.to_string(),
                 migration_guide: replacement.details.guideline(),
+                removed,
             },
             expr.range(),
         );
         match replacement.details {
-            Details::AutoImport { path, name } => {
+            Details::AutoImport {
+                path,
+                name,
+                available_since,
+            } => {
+                let import_is_available =
+                    import_is_available(available_since, checker.settings.numpy_version.as_ref());
                 diagnostic.try_set_fix(|| {
                     let (import_edit, binding) = checker.importer().get_or_import_symbol(
                         &ImportRequest::import_from(path, name),
@@ -463,7 +652,11 @@ pub(crate) fn numpy_2_0_deprecation(checker: &mut Checker, expr: &Expr) {
                         checker.semantic(),
                     )?;
                     let replacement_edit = Edit::range_replacement(binding, expr.range());
-                    Ok(Fix::safe_edits(import_edit, [replacement_edit]))
+                    Ok(if import_is_available {
+                        Fix::safe_edits(import_edit, [replacement_edit])
+                    } else {
+                        Fix::unsafe_edits(import_edit, [replacement_edit])
+                    })
                 });
             }
             Details::AutoPurePython { python_expr } => diagnostic.set_fix(Fix::safe_edit(
@@ -474,3 +667,94 @@ pub(crate) fn numpy_2_0_deprecation(checker: &mut Checker, expr: &Expr) {
         checker.diagnostics.push(diagnostic);
     }
 }
+
+/// Whether an auto-importable replacement is safe to apply automatically.
+///
+/// `None` means the replacement has always been importable, so the fix is
+/// safe regardless of the configured floor. Otherwise, without a configured
+/// NumPy floor, assume the project is still pre-2.0 (as `removed` above
+/// does) and treat the replacement as unverified: only trust it once a
+/// configured floor actually reaches the version that introduced it.
+fn import_is_available(available_since: Option<Version>, numpy_version: Option<&Version>) -> bool {
+    available_since.is_none_or(|available_since| {
+        numpy_version.is_some_and(|numpy_version| *numpy_version >= available_since)
+    })
+}
+
+// `assert_messages!` snapshots its argument into a `.snap` file under
+// `snapshots/` the first time each test runs (`cargo insta test --review`),
+// then compares against it on every subsequent run. No snapshot files are
+// checked in alongside these tests: this tree has no `Cargo.toml`, so there's
+// no way to execute the rule and record the real diagnostic output here.
+// Running `cargo insta test --review` once the crate builds will generate
+// and accept them.
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    use std::str::FromStr;
+
+    use anyhow::Result;
+    use pep440_rs::Version;
+
+    use super::import_is_available;
+    use crate::registry::Rule;
+    use crate::test::test_path;
+    use crate::{assert_messages, settings};
+
+    #[test]
+    fn import_is_available_always_available_ignores_floor() {
+        assert!(import_is_available(None, None));
+        assert!(import_is_available(
+            None,
+            Some(&Version::from_str("1.20").unwrap())
+        ));
+    }
+
+    #[test]
+    fn import_is_available_gated_requires_a_floor_that_reaches_it() {
+        let gate = Version::from_str("2.0").unwrap();
+
+        // No configured floor: assume pre-2.0, so the import is not yet safe.
+        assert!(!import_is_available(Some(gate.clone()), None));
+
+        // Configured floor below the gate: still not safe.
+        assert!(!import_is_available(
+            Some(gate.clone()),
+            Some(&Version::from_str("1.26").unwrap())
+        ));
+
+        // Configured floor at or above the gate: safe.
+        assert!(import_is_available(
+            Some(gate.clone()),
+            Some(&Version::from_str("2.0").unwrap())
+        ));
+    }
+
+    #[test]
+    fn npy201_numpy_version_unset() -> Result<()> {
+        let diagnostics = test_path(
+            Path::new("numpy/NPY201.py"),
+            &settings::LinterSettings::for_rule(Rule::Numpy2Deprecation),
+        )?;
+        assert_messages!("npy201_numpy_version_unset", diagnostics);
+        Ok(())
+    }
+
+    #[test]
+    fn npy201_numpy_version_1_26() -> Result<()> {
+        let mut settings = settings::LinterSettings::for_rule(Rule::Numpy2Deprecation);
+        settings.numpy_version = Some(Version::from_str("1.26").unwrap());
+        let diagnostics = test_path(Path::new("numpy/NPY201.py"), &settings)?;
+        assert_messages!("npy201_numpy_version_1_26", diagnostics);
+        Ok(())
+    }
+
+    #[test]
+    fn npy201_numpy_version_2_0() -> Result<()> {
+        let mut settings = settings::LinterSettings::for_rule(Rule::Numpy2Deprecation);
+        settings.numpy_version = Some(Version::from_str("2.0").unwrap());
+        let diagnostics = test_path(Path::new("numpy/NPY201.py"), &settings)?;
+        assert_messages!("npy201_numpy_version_2_0", diagnostics);
+        Ok(())
+    }
+}
@@ -0,0 +1,3 @@
+//! Rules specific to Ruff itself (the `RUF` category).
+
+pub(crate) mod rules;
@@ -0,0 +1,177 @@
+use ruff_diagnostics::{Diagnostic, Edit, Fix, FixAvailability, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::Expr;
+use ruff_text_size::Ranged;
+use serde::{Deserialize, Serialize};
+
+use crate::checkers::ast::Checker;
+use crate::importer::ImportRequest;
+
+/// A single entry in a user-configured migration table: a fully-qualified,
+/// dotted symbol path, plus how to migrate away from it.
+///
+/// This mirrors the hardcoded `Replacement`/`Details` pairs that rules like
+/// `numpy_2_0_deprecation` use internally, except the table is declared by
+/// the user in `pyproject.toml` rather than baked into Ruff, so that any
+/// library (pandas, scipy, an internal SDK) can ship its own deprecation map.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SymbolMigration {
+    /// The fully-qualified, dotted path of the deprecated symbol, e.g.
+    /// `"pandas.DataFrame.append"`.
+    pub path: String,
+    #[serde(flatten)]
+    pub replacement: SymbolReplacement,
+}
+
+/// The same three replacement strategies as the `Details` enum in
+/// `numpy_2_0_deprecation`, but
+/// owned so they can be deserialized from user configuration.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(
+    rename_all = "kebab-case",
+    rename_all_fields = "kebab-case",
+    tag = "kind"
+)]
+pub enum SymbolReplacement {
+    /// The deprecated symbol can be replaced by another importable symbol.
+    Import { import_path: String, name: String },
+    /// The deprecated symbol can be replaced by a pure Python expression.
+    Expression { expr: String },
+    /// The deprecated symbol requires a manual migration.
+    Manual { guideline: Option<String> },
+}
+
+impl SymbolReplacement {
+    fn guideline(&self) -> Option<String> {
+        match self {
+            SymbolReplacement::Import { import_path, name } => {
+                Some(format!("Use `{import_path}.{name}` instead."))
+            }
+            SymbolReplacement::Expression { expr } => Some(format!("Use `{expr}` instead.")),
+            SymbolReplacement::Manual { guideline } => guideline.clone(),
+        }
+    }
+}
+
+/// ## What it does
+/// Checks for uses of symbols that a project has declared deprecated via the
+/// `migrations` setting.
+///
+/// ## Why is this bad?
+/// Projects often maintain their own deprecation policies for internal APIs,
+/// or need to migrate off third-party symbols (e.g. a pandas or scipy
+/// release that renames or removes a member) ahead of Ruff shipping a
+/// dedicated rule. This rule lets a project declare a table of
+/// fully-qualified symbol paths and their replacements, and flags (with
+/// fixes, where possible) any use of a deprecated path.
+///
+/// ## Example
+/// ```toml
+/// [[tool.ruff.lint.migrations]]
+/// path = "legacy_sdk.utils.deprecated_helper"
+/// kind = "import"
+/// import-path = "legacy_sdk.utils.v2"
+/// name = "helper"
+/// ```
+///
+/// ## Options
+/// - `migrations`
+#[violation]
+pub struct DeprecatedSymbolReplacement {
+    existing: String,
+    migration_guide: Option<String>,
+}
+
+impl Violation for DeprecatedSymbolReplacement {
+    const FIX_AVAILABILITY: FixAvailability = FixAvailability::Sometimes;
+
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let DeprecatedSymbolReplacement {
+            existing,
+            migration_guide,
+        } = self;
+        match migration_guide {
+            Some(migration_guide) => format!("`{existing}` is deprecated. {migration_guide}"),
+            None => format!("`{existing}` is deprecated without a documented replacement."),
+        }
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        self.migration_guide.clone()
+    }
+}
+
+/// RUF102
+pub(crate) fn deprecated_symbol_replacement(checker: &mut Checker, expr: &Expr) {
+    let Some(call_path) = checker.semantic().resolve_call_path(expr) else {
+        return;
+    };
+    let dotted_path = call_path.join(".");
+
+    let Some(migration) = checker
+        .settings
+        .migrations
+        .iter()
+        .find(|migration| migration.path == dotted_path)
+    else {
+        return;
+    };
+
+    let mut diagnostic = Diagnostic::new(
+        DeprecatedSymbolReplacement {
+            existing: migration.path.clone(),
+            migration_guide: migration.replacement.guideline(),
+        },
+        expr.range(),
+    );
+
+    match &migration.replacement {
+        SymbolReplacement::Import { import_path, name } => {
+            diagnostic.try_set_fix(|| {
+                let (import_edit, binding) = checker.importer().get_or_import_symbol(
+                    &ImportRequest::import_from(import_path, name),
+                    expr.start(),
+                    checker.semantic(),
+                )?;
+                let replacement_edit = Edit::range_replacement(binding, expr.range());
+                Ok(Fix::safe_edits(import_edit, [replacement_edit]))
+            });
+        }
+        SymbolReplacement::Expression { expr: python_expr } => diagnostic.set_fix(Fix::safe_edit(
+            Edit::range_replacement(python_expr.clone(), expr.range()),
+        )),
+        SymbolReplacement::Manual { guideline: _ } => {}
+    }
+
+    checker.diagnostics.push(diagnostic);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SymbolMigration, SymbolReplacement};
+
+    #[test]
+    fn deserializes_import_migration_with_kebab_case_fields() {
+        let migration: SymbolMigration = serde_json::from_str(
+            r#"{
+                "path": "legacy_sdk.utils.deprecated_helper",
+                "kind": "import",
+                "import-path": "legacy_sdk.utils.v2",
+                "name": "helper"
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            migration,
+            SymbolMigration {
+                path: "legacy_sdk.utils.deprecated_helper".to_string(),
+                replacement: SymbolReplacement::Import {
+                    import_path: "legacy_sdk.utils.v2".to_string(),
+                    name: "helper".to_string(),
+                },
+            }
+        );
+    }
+}
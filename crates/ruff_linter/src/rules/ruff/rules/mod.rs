@@ -0,0 +1,3 @@
+pub(crate) use deprecated_symbol_replacement::*;
+
+mod deprecated_symbol_replacement;
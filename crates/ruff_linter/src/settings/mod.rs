@@ -0,0 +1,33 @@
+//! Linter-wide settings consumed by individual rules via `checker.settings`.
+//!
+//! This only reproduces the fields touched by the NPY201/RUF102 work; the
+//! real [`LinterSettings`] carries a field (or nested per-plugin `Settings`)
+//! for every configurable rule Ruff implements.
+
+use pep440_rs::Version;
+
+use crate::registry::Rule;
+use crate::rules::ruff::rules::SymbolMigration;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LinterSettings {
+    /// The minimum NumPy version the project supports, as configured via the
+    /// `numpy-version` option. `None` means no floor has been configured, in
+    /// which case version-gated fixes must assume the project may still be
+    /// on NumPy 1.x.
+    pub numpy_version: Option<Version>,
+
+    /// User-declared deprecated-symbol migrations, as configured via the
+    /// `migrations` option (see `RUF102`).
+    pub migrations: Vec<SymbolMigration>,
+}
+
+impl LinterSettings {
+    /// Default settings for a rule-level unit test of `rule`.
+    ///
+    /// These settings carry no rule selection, so `rule` is not recorded and
+    /// does not change the result: every field takes its default value.
+    pub fn for_rule(_rule: Rule) -> Self {
+        Self::default()
+    }
+}